@@ -0,0 +1,136 @@
+use cuda_sys::wrapper::handle::{CudaDevice, CudaStream};
+use cuda_sys::wrapper::mem::DeviceBuffer;
+
+use crate::tensor::DeviceTensor;
+
+/// A handle to a single CUDA device.
+///
+/// `Device` is a thin, `Copy`able wrapper around a device index: it doesn't own any CUDA
+/// resources itself, it's just the key used to set the current context before allocating memory
+/// or launching kernels on it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Device {
+    inner: CudaDevice,
+}
+
+impl Device {
+    pub fn new(index: i32) -> Device {
+        Device { inner: CudaDevice::new(index) }
+    }
+
+    pub fn inner(self) -> CudaDevice {
+        self.inner
+    }
+
+    /// Allocate `size` bytes of uninitialized device memory on this device.
+    pub fn alloc(self, size: usize) -> DeviceBuffer {
+        DeviceBuffer::alloc(self.inner, size)
+    }
+
+    /// The number of CUDA devices visible to this process.
+    pub fn count() -> i32 {
+        CudaDevice::count()
+    }
+
+    /// Every CUDA device visible to this process, in device-index order.
+    pub fn all() -> Vec<Device> {
+        (0..Device::count()).map(Device::new).collect()
+    }
+}
+
+/// One `CudaStream` per visible GPU, used to split a batched workload across all of them.
+///
+/// Devices are iterated over sequentially rather than through something like rayon: CUDA
+/// contexts are thread-local, so driving multiple devices from a pool of worker threads without
+/// very careful context management is a common source of "invalid device context" errors. A
+/// single thread round-robining over devices with one stream each sidesteps that entirely.
+pub struct MultiDevice {
+    devices: Vec<Device>,
+    streams: Vec<CudaStream>,
+}
+
+impl MultiDevice {
+    /// Open a stream on every visible GPU.
+    pub fn all() -> MultiDevice {
+        let devices = Device::all();
+        let streams = devices.iter().map(|&device| CudaStream::new(device.inner())).collect();
+        MultiDevice { devices, streams }
+    }
+
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    pub fn devices(&self) -> &[Device] {
+        &self.devices
+    }
+
+    pub fn streams(&self) -> &[CudaStream] {
+        &self.streams
+    }
+
+    /// Split `batch_size` rows across the visible devices in shards of
+    /// `ceil(batch_size / n)` rows, where `n` is the smaller of the device count and `batch_size`
+    /// (the last shard holds whatever is left, which may be
+    /// fewer rows, and devices past the end of the batch get no shard), upload the corresponding
+    /// `row_len`-wide slice of `input_data` to each device, call `launch` once per shard with that
+    /// device's input/output tensors, then synchronize every stream and concatenate the
+    /// `output_row_len`-wide shard outputs back into a single host-side result in original batch
+    /// order.
+    ///
+    /// Shards are dispatched one device at a time (see the [`MultiDevice`] docs for why), so the
+    /// work `launch` enqueues on one device's stream can still be running while the next shard is
+    /// uploaded; only the final gather actually waits for completion.
+    pub fn dispatch_batched_rows(
+        &self,
+        batch_size: usize,
+        row_len: usize,
+        output_row_len: usize,
+        input_data: &[f32],
+        mut launch: impl FnMut(usize, Device, &CudaStream, &DeviceTensor<f32>, &DeviceTensor<f32>),
+    ) -> Vec<f32> {
+        assert_eq!(input_data.len(), batch_size * row_len);
+        assert!(!self.is_empty(), "no CUDA devices visible");
+
+        let shard_count = self.len().min(batch_size.max(1));
+        let shard_size = (batch_size + shard_count - 1) / shard_count.max(1);
+
+        let mut shards = Vec::with_capacity(shard_count);
+        for (shard_index, (&device, stream)) in self.devices.iter().zip(&self.streams).enumerate().take(shard_count) {
+            let start = shard_index * shard_size;
+            if start >= batch_size {
+                break;
+            }
+            let end = (start + shard_size).min(batch_size);
+            let rows = end - start;
+
+            let input = DeviceTensor::<f32>::alloc(device, vec![rows, row_len]);
+            let output = DeviceTensor::<f32>::alloc(device, vec![rows, output_row_len]);
+            unsafe {
+                input.buffer().copy_linear_from_host(bytemuck::cast_slice(&input_data[start * row_len..end * row_len]));
+            }
+
+            launch(shard_index, device, stream, &input, &output);
+            shards.push((start, end, output));
+        }
+
+        for stream in &self.streams {
+            stream.synchronize();
+        }
+
+        let mut result = vec![0f32; batch_size * output_row_len];
+        for (start, end, output) in &shards {
+            let rows = end - start;
+            let mut shard_data = vec![0f32; rows * output_row_len];
+            unsafe {
+                output.buffer().copy_linear_to_host(bytemuck::cast_slice_mut(&mut shard_data));
+            }
+            result[start * output_row_len..end * output_row_len].copy_from_slice(&shard_data);
+        }
+        result
+    }
+}
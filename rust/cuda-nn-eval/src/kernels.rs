@@ -0,0 +1,607 @@
+//! Safe wrappers around the CUDA kernels defined in `cuda/kernels.cu`.
+//!
+//! Every function here launches a single kernel on the given stream and returns as soon as the
+//! launch has been enqueued (the stream itself is not synchronized). The shapes/strides passed
+//! in are not validated here, since the kernels themselves are only ever called through `nn-graph`
+//! executor code that has already checked them.
+
+use std::os::raw::c_void;
+
+use half::{bf16, f16};
+
+use cuda_sys::bindings::CUstream_st;
+use cuda_sys::wrapper::status::Status;
+
+mod raw {
+    use std::os::raw::c_void;
+
+    use cuda_sys::bindings::CUstream_st;
+
+    extern "C" {
+        pub fn stridedCopyFloat(
+            stream: *mut CUstream_st,
+            rank: i32,
+            size: i32,
+            input_strides: *const i32,
+            output_strides: *const i32,
+            dense_strides: *const i32,
+            input: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn gatherFloat(
+            stream: *mut CUstream_st,
+            index_count: i32,
+            indices: *const c_void,
+            input: *const c_void,
+            output: *mut c_void,
+        ) -> i32;
+
+        pub fn gather2dAxis1FloatFloat(
+            stream: *mut CUstream_st,
+            batch_size: i32,
+            input_size: i32,
+            input_stride: i32,
+            input_stride_axis: i32,
+            index_count: i32,
+            input: *const f32,
+            indices: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn scatterAddFloat(
+            stream: *mut CUstream_st,
+            index_count: i32,
+            indices: *const i32,
+            source: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn scatterAdd2dAxis1Float(
+            stream: *mut CUstream_st,
+            batch_size: i32,
+            output_stride: i32,
+            output_stride_axis: i32,
+            index_count: i32,
+            source: *const f32,
+            indices: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn layerNormFloat(
+            stream: *mut CUstream_st,
+            rows: i32,
+            cols: i32,
+            eps: f32,
+            input: *const f32,
+            gamma: *const f32,
+            beta: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn geluFloat(stream: *mut CUstream_st, length: i32, input: *const f32, output: *mut f32) -> i32;
+
+        pub fn quantize(stream: *mut CUstream_st, length: i32, input: *const c_void, output: *mut u8) -> i32;
+
+        pub fn unquantize(stream: *mut CUstream_st, length: i32, input: *const u8, output: *mut c_void) -> i32;
+
+        pub fn quantizeAffine(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const f32,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut u8,
+        ) -> i32;
+
+        pub fn dequantizeAffine(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const u8,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn quantizeAffineSymmetric(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const f32,
+            scale: *const f32,
+            output: *mut i8,
+        ) -> i32;
+
+        pub fn dequantizeAffineSymmetric(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const i8,
+            scale: *const f32,
+            output: *mut f32,
+        ) -> i32;
+
+        pub fn calibrateMinMax(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const f32,
+            min: *mut f32,
+            max: *mut f32,
+        ) -> i32;
+
+        pub fn stridedCopyHalf(
+            stream: *mut CUstream_st,
+            rank: i32,
+            size: i32,
+            input_strides: *const i32,
+            output_strides: *const i32,
+            dense_strides: *const i32,
+            input: *const f16,
+            output: *mut f16,
+        ) -> i32;
+
+        pub fn stridedCopyBFloat16(
+            stream: *mut CUstream_st,
+            rank: i32,
+            size: i32,
+            input_strides: *const i32,
+            output_strides: *const i32,
+            dense_strides: *const i32,
+            input: *const bf16,
+            output: *mut bf16,
+        ) -> i32;
+
+        pub fn gatherHalf(
+            stream: *mut CUstream_st,
+            index_count: i32,
+            indices: *const i32,
+            input: *const f16,
+            output: *mut f16,
+        ) -> i32;
+
+        pub fn gatherBFloat16(
+            stream: *mut CUstream_st,
+            index_count: i32,
+            indices: *const i32,
+            input: *const bf16,
+            output: *mut bf16,
+        ) -> i32;
+
+        pub fn gather2dAxis1HalfHalf(
+            stream: *mut CUstream_st,
+            batch_size: i32,
+            input_size: i32,
+            input_stride: i32,
+            input_stride_axis: i32,
+            index_count: i32,
+            input: *const f16,
+            indices: *const f16,
+            output: *mut f16,
+        ) -> i32;
+
+        pub fn gather2dAxis1BFloat16BFloat16(
+            stream: *mut CUstream_st,
+            batch_size: i32,
+            input_size: i32,
+            input_stride: i32,
+            input_stride_axis: i32,
+            index_count: i32,
+            input: *const bf16,
+            indices: *const bf16,
+            output: *mut bf16,
+        ) -> i32;
+
+        pub fn quantizeAffineHalf(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const f16,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut u8,
+        ) -> i32;
+
+        pub fn dequantizeAffineHalf(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const u8,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut f16,
+        ) -> i32;
+
+        pub fn quantizeAffineBFloat16(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const bf16,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut u8,
+        ) -> i32;
+
+        pub fn dequantizeAffineBFloat16(
+            stream: *mut CUstream_st,
+            length: i32,
+            channel_count: i32,
+            channel_stride: i32,
+            input: *const u8,
+            scale: *const f32,
+            zero_point: *const i32,
+            output: *mut bf16,
+        ) -> i32;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn stridedCopyFloat(
+    stream: *mut CUstream_st,
+    rank: i32,
+    size: i32,
+    input_strides: *const i32,
+    output_strides: *const i32,
+    dense_strides: *const i32,
+    input: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::stridedCopyFloat(stream, rank, size, input_strides, output_strides, dense_strides, input, output)
+    })
+}
+
+pub fn gatherFloat(
+    stream: *mut CUstream_st,
+    index_count: i32,
+    indices: *const c_void,
+    input: *const c_void,
+    output: *mut c_void,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::gatherFloat(stream, index_count, indices, input, output) })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gather2dAxis1FloatFloat(
+    stream: *mut CUstream_st,
+    batch_size: i32,
+    input_size: i32,
+    input_stride: i32,
+    input_stride_axis: i32,
+    index_count: i32,
+    input: *const f32,
+    indices: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::gather2dAxis1FloatFloat(
+            stream,
+            batch_size,
+            input_size,
+            input_stride,
+            input_stride_axis,
+            index_count,
+            input,
+            indices,
+            output,
+        )
+    })
+}
+
+/// Gradient/inverse of [`gatherFloat`]: accumulates `source[i]` into `output[indices[i]]` with
+/// `atomicAdd`, so overlapping indices sum rather than race. `output` is accumulated into, not
+/// overwritten, so callers must zero it first unless they intend to add onto existing values.
+pub fn scatterAddFloat(
+    stream: *mut CUstream_st,
+    index_count: i32,
+    indices: *const i32,
+    source: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::scatterAddFloat(stream, index_count, indices, source, output) })
+}
+
+/// Gradient/inverse of [`gather2dAxis1FloatFloat`]: accumulates `source[batch, q]` into
+/// `output[batch, indices[q]]` with `atomicAdd`.
+#[allow(clippy::too_many_arguments)]
+pub fn scatterAdd2dAxis1Float(
+    stream: *mut CUstream_st,
+    batch_size: i32,
+    output_stride: i32,
+    output_stride_axis: i32,
+    index_count: i32,
+    source: *const f32,
+    indices: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::scatterAdd2dAxis1Float(stream, batch_size, output_stride, output_stride_axis, index_count, source, indices, output)
+    })
+}
+
+/// Fused LayerNorm over `rows` rows of `cols` elements each: per row, normalizes by the row's
+/// own mean/variance and applies the learned `gamma`/`beta` (each `cols` long), all in one
+/// launch.
+#[allow(clippy::too_many_arguments)]
+pub fn layerNormFloat(
+    stream: *mut CUstream_st,
+    rows: i32,
+    cols: i32,
+    eps: f32,
+    input: *const f32,
+    gamma: *const f32,
+    beta: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::layerNormFloat(stream, rows, cols, eps, input, gamma, beta, output) })
+}
+
+/// Elementwise GELU (tanh approximation).
+pub fn geluFloat(stream: *mut CUstream_st, length: i32, input: *const f32, output: *mut f32) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::geluFloat(stream, length, input, output) })
+}
+
+pub fn quantize(stream: *mut CUstream_st, length: i32, input: *const c_void, output: *mut u8) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::quantize(stream, length, input, output) })
+}
+
+pub fn unquantize(stream: *mut CUstream_st, length: i32, input: *const u8, output: *mut c_void) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::unquantize(stream, length, input, output) })
+}
+
+/// Affine-quantize `input` into `output`, one `scale`/`zero_point` pair per `channel_count`
+/// contiguous `channel_stride`-sized run of `input` (pass `channel_count == 1` for per-tensor
+/// quantization). Values are clamped to `[0, 255]`.
+#[allow(clippy::too_many_arguments)]
+pub fn quantizeAffine(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const f32,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut u8,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::quantizeAffine(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
+
+/// Inverse of [`quantizeAffine`]: `output[i] = (input[i] - zero_point[c]) * scale[c]`.
+#[allow(clippy::too_many_arguments)]
+pub fn dequantizeAffine(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const u8,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::dequantizeAffine(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
+
+/// Symmetric variant of [`quantizeAffine`] with an implicit zero point of zero, clamped to
+/// `[-127, 127]`.
+pub fn quantizeAffineSymmetric(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const f32,
+    scale: *const f32,
+    output: *mut i8,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::quantizeAffineSymmetric(stream, length, channel_count, channel_stride, input, scale, output) })
+}
+
+/// Inverse of [`quantizeAffineSymmetric`].
+pub fn dequantizeAffineSymmetric(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const i8,
+    scale: *const f32,
+    output: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::dequantizeAffineSymmetric(stream, length, channel_count, channel_stride, input, scale, output) })
+}
+
+/// Calibration pass for [`quantizeAffine`]: reduces `input` down to one observed `(min, max)`
+/// pair per channel, writing `channel_count` entries into `min`/`max`.
+pub fn calibrateMinMax(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const f32,
+    min: *mut f32,
+    max: *mut f32,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::calibrateMinMax(stream, length, channel_count, channel_stride, input, min, max) })
+}
+
+/// f16 variant of [`stridedCopyFloat`].
+#[allow(clippy::too_many_arguments)]
+pub fn stridedCopyHalf(
+    stream: *mut CUstream_st,
+    rank: i32,
+    size: i32,
+    input_strides: *const i32,
+    output_strides: *const i32,
+    dense_strides: *const i32,
+    input: *const f16,
+    output: *mut f16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::stridedCopyHalf(stream, rank, size, input_strides, output_strides, dense_strides, input, output)
+    })
+}
+
+/// bf16 variant of [`stridedCopyFloat`].
+#[allow(clippy::too_many_arguments)]
+pub fn stridedCopyBFloat16(
+    stream: *mut CUstream_st,
+    rank: i32,
+    size: i32,
+    input_strides: *const i32,
+    output_strides: *const i32,
+    dense_strides: *const i32,
+    input: *const bf16,
+    output: *mut bf16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::stridedCopyBFloat16(stream, rank, size, input_strides, output_strides, dense_strides, input, output)
+    })
+}
+
+/// f16 variant of [`gatherFloat`].
+pub fn gatherHalf(
+    stream: *mut CUstream_st,
+    index_count: i32,
+    indices: *const i32,
+    input: *const f16,
+    output: *mut f16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::gatherHalf(stream, index_count, indices, input, output) })
+}
+
+/// bf16 variant of [`gatherFloat`].
+pub fn gatherBFloat16(
+    stream: *mut CUstream_st,
+    index_count: i32,
+    indices: *const i32,
+    input: *const bf16,
+    output: *mut bf16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe { raw::gatherBFloat16(stream, index_count, indices, input, output) })
+}
+
+/// f16 variant of [`gather2dAxis1FloatFloat`].
+#[allow(clippy::too_many_arguments)]
+pub fn gather2dAxis1HalfHalf(
+    stream: *mut CUstream_st,
+    batch_size: i32,
+    input_size: i32,
+    input_stride: i32,
+    input_stride_axis: i32,
+    index_count: i32,
+    input: *const f16,
+    indices: *const f16,
+    output: *mut f16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::gather2dAxis1HalfHalf(stream, batch_size, input_size, input_stride, input_stride_axis, index_count, input, indices, output)
+    })
+}
+
+/// bf16 variant of [`gather2dAxis1FloatFloat`].
+#[allow(clippy::too_many_arguments)]
+pub fn gather2dAxis1BFloat16BFloat16(
+    stream: *mut CUstream_st,
+    batch_size: i32,
+    input_size: i32,
+    input_stride: i32,
+    input_stride_axis: i32,
+    index_count: i32,
+    input: *const bf16,
+    indices: *const bf16,
+    output: *mut bf16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::gather2dAxis1BFloat16BFloat16(
+            stream,
+            batch_size,
+            input_size,
+            input_stride,
+            input_stride_axis,
+            index_count,
+            input,
+            indices,
+            output,
+        )
+    })
+}
+
+/// f16 variant of [`quantizeAffine`].
+#[allow(clippy::too_many_arguments)]
+pub fn quantizeAffineHalf(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const f16,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut u8,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::quantizeAffineHalf(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
+
+/// f16 variant of [`dequantizeAffine`].
+#[allow(clippy::too_many_arguments)]
+pub fn dequantizeAffineHalf(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const u8,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut f16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::dequantizeAffineHalf(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
+
+/// bf16 variant of [`quantizeAffine`].
+#[allow(clippy::too_many_arguments)]
+pub fn quantizeAffineBFloat16(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const bf16,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut u8,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::quantizeAffineBFloat16(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
+
+/// bf16 variant of [`dequantizeAffine`].
+#[allow(clippy::too_many_arguments)]
+pub fn dequantizeAffineBFloat16(
+    stream: *mut CUstream_st,
+    length: i32,
+    channel_count: i32,
+    channel_stride: i32,
+    input: *const u8,
+    scale: *const f32,
+    zero_point: *const i32,
+    output: *mut bf16,
+) -> Result<(), Status> {
+    Status::wrap(unsafe {
+        raw::dequantizeAffineBFloat16(stream, length, channel_count, channel_stride, input, scale, zero_point, output)
+    })
+}
@@ -0,0 +1,11 @@
+//! CUDA-backed evaluation kernels and device tensors for `nn-graph`.
+//!
+//! This crate is the thin runtime layer that sits on top of `cuda-sys`: it owns the `Device`
+//! handle, wraps raw buffers in `DeviceTensor`, and exposes the individual CUDA kernels used to
+//! evaluate a graph (copies, gathers, quantization, ...) through the `kernels` module.
+
+pub mod device;
+pub mod kernels;
+pub mod tensor;
+
+pub use device::{Device, MultiDevice};
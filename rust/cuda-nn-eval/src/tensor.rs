@@ -0,0 +1,173 @@
+use std::marker::PhantomData;
+
+use bytemuck::cast_slice_mut;
+use half::{bf16, f16};
+
+use cuda_sys::wrapper::handle::CudaStream;
+use cuda_sys::wrapper::mem::DeviceBuffer;
+
+use crate::kernels;
+use crate::Device;
+
+/// A scalar type a [`DeviceTensor`] can hold.
+///
+/// This only exists so `DeviceTensor<T>` can size its own allocation; it carries no behavior of
+/// its own, the actual per-dtype kernels live in `kernels` and are picked by the caller.
+pub trait Element: Copy + 'static {}
+
+impl Element for f32 {}
+impl Element for f16 {}
+impl Element for bf16 {}
+
+/// A dense tensor living in device memory, with an explicit shape and dense row-major strides.
+///
+/// `DeviceTensor` is the unit the executor passes around between kernels; it doesn't know
+/// anything about the graph it came from, it's just a `(shape, buffer)` pair plus the device it
+/// was allocated on. `T` defaults to `f32`; mixed-precision graphs use `DeviceTensor<f16>` /
+/// `DeviceTensor<bf16>` for weights and activations that don't need full precision.
+#[derive(Debug, Clone)]
+pub struct DeviceTensor<T: Element = f32> {
+    device: Device,
+    shape: Vec<usize>,
+    buffer: DeviceBuffer,
+    element: PhantomData<T>,
+}
+
+impl<T: Element> DeviceTensor<T> {
+    pub fn alloc(device: Device, shape: Vec<usize>) -> DeviceTensor<T> {
+        let len: usize = shape.iter().product();
+        let buffer = device.alloc(len * std::mem::size_of::<T>());
+        DeviceTensor { device, shape, buffer, element: PhantomData }
+    }
+
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn buffer(&self) -> &DeviceBuffer {
+        &self.buffer
+    }
+
+    pub fn ptr(&self) -> *const T {
+        self.buffer.ptr() as *const T
+    }
+
+    pub fn ptr_mut(&self) -> *mut T {
+        self.buffer.ptr() as *mut T
+    }
+}
+
+impl DeviceTensor<f32> {
+    /// Fused LayerNorm: treats `self` as `(rows, cols)` with `cols` the last dimension of
+    /// `shape`, and writes the normalized, gamma/beta-scaled result into `output`.
+    pub fn layer_norm(&self, stream: &CudaStream, gamma: &DeviceTensor<f32>, beta: &DeviceTensor<f32>, eps: f32, output: &DeviceTensor<f32>) {
+        let cols = *self.shape.last().expect("layer_norm needs a non-scalar tensor") as i32;
+        let rows = self.len() as i32 / cols;
+        assert_eq!(gamma.len(), cols as usize);
+        assert_eq!(beta.len(), cols as usize);
+        assert_eq!(output.shape(), self.shape());
+
+        unsafe {
+            kernels::layerNormFloat(stream.inner(), rows, cols, eps, self.ptr(), gamma.ptr(), beta.ptr(), output.ptr_mut()).unwrap();
+        }
+    }
+
+    /// Elementwise GELU, written into `output`.
+    pub fn gelu(&self, stream: &CudaStream, output: &DeviceTensor<f32>) {
+        assert_eq!(output.shape(), self.shape());
+        unsafe {
+            kernels::geluFloat(stream.inner(), self.len() as i32, self.ptr(), output.ptr_mut()).unwrap();
+        }
+    }
+
+    /// Run a calibration pass over this tensor and derive affine quantization parameters from
+    /// the observed range.
+    ///
+    /// The tensor is split into `channel_count` equal contiguous channels (pass `1` for plain
+    /// per-tensor quantization); `symmetric` selects between a `[-127, 127]` quantization around
+    /// zero or a `[0, 255]` quantization with a real zero point.
+    ///
+    /// The channel axis must be the leading (outermost, largest-stride) dimension: channels are
+    /// treated as `channel_count` contiguous `channel_stride`-sized runs of `self`, so there's no
+    /// way to calibrate per-channel along an interleaved (e.g. innermost) axis with this method.
+    pub fn calibrate_affine(&self, stream: &CudaStream, channel_count: usize, symmetric: bool) -> AffineQuantization {
+        assert_eq!(self.len() % channel_count, 0, "tensor length must be divisible by channel_count");
+        let channel_stride = self.len() / channel_count;
+
+        let min_buffer = self.device.alloc(channel_count * std::mem::size_of::<f32>());
+        let max_buffer = self.device.alloc(channel_count * std::mem::size_of::<f32>());
+
+        unsafe {
+            kernels::calibrateMinMax(
+                stream.inner(),
+                self.len() as i32,
+                channel_count as i32,
+                channel_stride as i32,
+                self.ptr(),
+                min_buffer.ptr() as *mut f32,
+                max_buffer.ptr() as *mut f32,
+            )
+            .unwrap();
+            stream.synchronize();
+        }
+
+        let mut min_data = vec![0f32; channel_count];
+        let mut max_data = vec![0f32; channel_count];
+        unsafe {
+            min_buffer.copy_linear_to_host(cast_slice_mut(&mut min_data));
+            max_buffer.copy_linear_to_host(cast_slice_mut(&mut max_data));
+        }
+
+        AffineQuantization::from_observed_range(&min_data, &max_data, symmetric)
+    }
+}
+
+/// Per-tensor or per-channel affine quantization parameters, as produced by
+/// [`DeviceTensor::calibrate_affine`] and consumed by `kernels::quantizeAffine` /
+/// `kernels::quantizeAffineSymmetric`.
+#[derive(Debug, Clone)]
+pub struct AffineQuantization {
+    pub scale: Vec<f32>,
+    pub zero_point: Vec<i32>,
+    pub symmetric: bool,
+}
+
+impl AffineQuantization {
+    /// Derive scale/zero-point pairs from an observed `(min, max)` range per channel.
+    pub fn from_observed_range(min: &[f32], max: &[f32], symmetric: bool) -> AffineQuantization {
+        assert_eq!(min.len(), max.len());
+
+        if symmetric {
+            let scale = min
+                .iter()
+                .zip(max)
+                .map(|(&lo, &hi)| (lo.abs().max(hi.abs()) / 127.0).max(f32::EPSILON))
+                .collect();
+            AffineQuantization { scale, zero_point: vec![0; min.len()], symmetric: true }
+        } else {
+            let scale: Vec<f32> = min.iter().zip(max).map(|(&lo, &hi)| ((hi - lo) / 255.0).max(f32::EPSILON)).collect();
+            let zero_point = min
+                .iter()
+                .zip(&scale)
+                .map(|(&lo, &s)| (-lo / s).round().clamp(0.0, 255.0) as i32)
+                .collect();
+            AffineQuantization { scale, zero_point, symmetric: false }
+        }
+    }
+
+    pub fn channel_count(&self) -> usize {
+        self.scale.len()
+    }
+}
@@ -1,10 +1,11 @@
 use bytemuck::{cast_slice, cast_slice_mut};
+use half::{bf16, f16};
 use itertools::Itertools;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 
 use cuda_nn_eval::tensor::DeviceTensor;
-use cuda_nn_eval::{kernels, Device};
+use cuda_nn_eval::{kernels, Device, MultiDevice};
 use cuda_sys::wrapper::event::CudaEvent;
 use cuda_sys::wrapper::handle::CudaStream;
 use cuda_sys::wrapper::status::Status;
@@ -58,6 +59,47 @@ fn strided_copy() {
     println!("{:?}", output_data);
 }
 
+/// Unlike `strided_copy` (whose strides are deliberately non-contiguous so the last axis has
+/// stride 2), this exercises a fully dense, 16-byte-aligned copy that actually takes the
+/// `stridedCopyFloat4Kernel` vec4 fast path, and checks the result is correct rather than just
+/// not crashing.
+#[test]
+fn strided_copy_contiguous_vec4() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data = (0..128).map(|x| x as f32).collect_vec();
+    let mut output_data = vec![0f32; 128];
+
+    let input = device.alloc(input_data.len() * 4);
+    let output = device.alloc(output_data.len() * 4);
+
+    let rank = 2;
+    let size = 128;
+    let strides: Vec<i32> = vec![16, 1];
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+
+        kernels::stridedCopyFloat(
+            stream.inner(),
+            rank,
+            size,
+            strides.as_ptr(),
+            strides.as_ptr(),
+            strides.as_ptr(),
+            input.ptr() as *const f32,
+            output.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    assert_eq!(output_data, input_data);
+}
+
 #[test]
 fn gather() {
     let device = Device::new(0);
@@ -183,6 +225,51 @@ fn gather_2d_axis1_impl(batch_size: usize, input_size: usize, index_count: usize
     }
 }
 
+#[test]
+fn multi_device_gather_2d_axis1() {
+    let multi_device = MultiDevice::all();
+
+    let batch_size = 37;
+    let input_size = 128;
+    let index_count = 17;
+
+    let input_data: Vec<f32> = (0..batch_size * input_size).map(|x| -(x as f32)).collect_vec();
+    let mut index_rng = StdRng::seed_from_u64(1);
+    let indices_data: Vec<f32> = (0..index_count).map(|_| index_rng.gen_range(0..input_size) as f32).collect_vec();
+
+    let output_data = multi_device.dispatch_batched_rows(
+        batch_size,
+        input_size,
+        index_count,
+        &input_data,
+        |_shard_index, device, stream, input, output| {
+            let indices = device.alloc(indices_data.len() * 4);
+            unsafe {
+                indices.copy_linear_from_host(cast_slice(&indices_data));
+
+                kernels::gather2dAxis1FloatFloat(
+                    stream.inner(),
+                    input.shape()[0] as i32,
+                    input_size as i32,
+                    input_size as i32,
+                    1,
+                    index_count as i32,
+                    input.ptr(),
+                    indices.ptr() as *const f32,
+                    output.ptr_mut(),
+                )
+                .unwrap();
+            }
+        },
+    );
+
+    let expected_output_data = (0..batch_size)
+        .flat_map(|n| indices_data.iter().map(|&i| input_data[n * input_size + i as usize]).collect_vec())
+        .collect_vec();
+
+    assert_eq!(output_data, expected_output_data);
+}
+
 #[test]
 fn quantize() {
     let device = Device::new(0);
@@ -225,3 +312,737 @@ fn quantize() {
     println!("{:?}", middle_data);
     println!("{:?}", output_data);
 }
+
+#[test]
+fn scatter_add() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let output_len = 8;
+    // Duplicate and out-of-order indices: index 2 is hit twice and should accumulate.
+    let indices_data: Vec<i32> = vec![5, 2, 0, 2, 7];
+    let source_data: Vec<f32> = indices_data.iter().map(|&i| (i + 1) as f32).collect_vec();
+    let mut output_data = vec![0f32; output_len];
+
+    let indices = device.alloc(indices_data.len() * 4);
+    let source = device.alloc(source_data.len() * 4);
+    let output = device.alloc(output_data.len() * 4);
+
+    unsafe {
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+        source.copy_linear_from_host(cast_slice(&source_data));
+        output.copy_linear_from_host(cast_slice(&output_data));
+
+        kernels::scatterAddFloat(
+            stream.inner(),
+            indices_data.len() as i32,
+            indices.ptr() as *const i32,
+            source.ptr() as *const f32,
+            output.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let mut expected_output_data = vec![0f32; output_len];
+    for (&index, &value) in indices_data.iter().zip(&source_data) {
+        expected_output_data[index as usize] += value;
+    }
+
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn scatter_add_2d_axis1() {
+    for batch_size in [0, 1, 2, 3, 4, 8, 13] {
+        for output_size in [1, 2, 3, 4, 128, 129, 1000] {
+            for index_count in [0, 1, 2, 3, 63, 64, 65, 127, 128, 129, 1000] {
+                scatter_add_2d_axis1_impl(batch_size, output_size, index_count);
+            }
+        }
+    }
+}
+
+fn scatter_add_2d_axis1_impl(batch_size: usize, output_size: usize, index_count: usize) {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let mut index_rng = StdRng::seed_from_u64(1);
+    // Indices are intentionally out of order and may repeat, to exercise the atomicAdd
+    // accumulation this kernel exists for.
+    let indices_data: Vec<f32> = (0..index_count)
+        .map(|_| index_rng.gen_range(0..output_size.max(1)) as f32)
+        .collect_vec();
+
+    let source_data: Vec<f32> = (0..batch_size * indices_data.len()).map(|x| x as f32).collect_vec();
+    let mut output_data: Vec<f32> = vec![0f32; batch_size * output_size];
+
+    let source = device.alloc(source_data.len() * 4);
+    let indices = device.alloc(indices_data.len() * 4);
+    let output = device.alloc(output_data.len() * 4);
+
+    unsafe {
+        source.copy_linear_from_host(cast_slice(&source_data));
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+        output.copy_linear_from_host(cast_slice(&output_data));
+
+        kernels::scatterAdd2dAxis1Float(
+            stream.inner(),
+            batch_size as i32,
+            output_size as i32,
+            1,
+            indices_data.len() as i32,
+            source.ptr() as *const f32,
+            indices.ptr() as *const f32,
+            output.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let mut expected_output_data = vec![0f32; batch_size * output_size];
+    for n in 0..batch_size {
+        for (q, &index) in indices_data.iter().enumerate() {
+            expected_output_data[n * output_size + index as usize] += source_data[n * index_count + q];
+        }
+    }
+
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn layer_norm() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let rows = 5;
+    let cols = 37;
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let input_data: Vec<f32> = (0..rows * cols).map(|_| rng.gen_range(-10.0..10.0)).collect_vec();
+    let gamma_data: Vec<f32> = (0..cols).map(|_| rng.gen_range(0.5..2.0)).collect_vec();
+    let beta_data: Vec<f32> = (0..cols).map(|_| rng.gen_range(-1.0..1.0)).collect_vec();
+    let mut output_data = vec![0f32; rows * cols];
+
+    let input = device.alloc(input_data.len() * 4);
+    let gamma = device.alloc(gamma_data.len() * 4);
+    let beta = device.alloc(beta_data.len() * 4);
+    let output = device.alloc(output_data.len() * 4);
+
+    let eps = 1e-5;
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        gamma.copy_linear_from_host(cast_slice(&gamma_data));
+        beta.copy_linear_from_host(cast_slice(&beta_data));
+
+        kernels::layerNormFloat(
+            stream.inner(),
+            rows as i32,
+            cols as i32,
+            eps,
+            input.ptr() as *const f32,
+            gamma.ptr() as *const f32,
+            beta.ptr() as *const f32,
+            output.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    for row in 0..rows {
+        let slice = &input_data[row * cols..(row + 1) * cols];
+        let mean = slice.iter().sum::<f32>() / cols as f32;
+        let variance = slice.iter().map(|&x| (x - mean) * (x - mean)).sum::<f32>() / cols as f32;
+        let rstd = 1.0 / (variance + eps).sqrt();
+
+        for col in 0..cols {
+            let expected = (input_data[row * cols + col] - mean) * rstd * gamma_data[col] + beta_data[col];
+            let actual = output_data[row * cols + col];
+            assert!((actual - expected).abs() < 1e-3, "row {} col {}: {} vs {}", row, col, actual, expected);
+        }
+    }
+}
+
+#[test]
+fn gelu() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<f32> = Array1::linspace(-5.0, 5.0, 64).to_vec();
+    let mut output_data = vec![0f32; input_data.len()];
+
+    let input = device.alloc(input_data.len() * 4);
+    let output = device.alloc(output_data.len() * 4);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+
+        kernels::geluFloat(stream.inner(), input_data.len() as i32, input.ptr() as *const f32, output.ptr() as *mut f32)
+            .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    for (&x, &y) in input_data.iter().zip(&output_data) {
+        let expected = 0.5 * x * (1.0 + ((2.0f32 / std::f32::consts::PI).sqrt() * (x + 0.044715 * x.powi(3))).tanh());
+        assert!((y - expected).abs() < 1e-3, "{} -> {}, expected {}", x, y, expected);
+    }
+}
+
+#[test]
+fn quantize_affine() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    // Two channels of 16 values each, with very different ranges: calibration should pick a
+    // separate scale/zero_point per channel rather than one shared range.
+    let channel_count = 2;
+    let channel_stride = 16;
+    let length = channel_count * channel_stride;
+
+    let input_data: Vec<f32> = (0..length)
+        .map(|i| {
+            let channel = i / channel_stride;
+            let x = (i % channel_stride) as f32 / (channel_stride - 1) as f32;
+            if channel == 0 {
+                x * 2.0 - 1.0 // [-1, 1]
+            } else {
+                x * 200.0 - 50.0 // [-50, 150]
+            }
+        })
+        .collect_vec();
+
+    let input = device.alloc(input_data.len() * 4);
+    let min = device.alloc(channel_count * 4);
+    let max = device.alloc(channel_count * 4);
+    let scale = device.alloc(channel_count * 4);
+    let zero_point = device.alloc(channel_count * 4);
+    let middle = device.alloc(length);
+    let output = device.alloc(length * 4);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+
+        kernels::calibrateMinMax(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            input.ptr() as *const f32,
+            min.ptr() as *mut f32,
+            max.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        let mut min_data = vec![0f32; channel_count];
+        let mut max_data = vec![0f32; channel_count];
+        min.copy_linear_to_host(cast_slice_mut(&mut min_data));
+        max.copy_linear_to_host(cast_slice_mut(&mut max_data));
+
+        let scale_data: Vec<f32> = min_data.iter().zip(&max_data).map(|(&lo, &hi)| (hi - lo) / 255.0).collect_vec();
+        let zero_point_data: Vec<i32> = min_data
+            .iter()
+            .zip(&scale_data)
+            .map(|(&lo, &s)| (-lo / s).round() as i32)
+            .collect_vec();
+        scale.copy_linear_from_host(cast_slice(&scale_data));
+        zero_point.copy_linear_from_host(cast_slice(&zero_point_data));
+
+        kernels::quantizeAffine(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            input.ptr() as *const f32,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            middle.ptr() as *mut u8,
+        )
+        .unwrap();
+        kernels::dequantizeAffine(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            middle.ptr() as *const u8,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            output.ptr() as *mut f32,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        let mut output_data = vec![0f32; length];
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+
+        for (i, (&x, &y)) in input_data.iter().zip(&output_data).enumerate() {
+            let channel = i / channel_stride;
+            let tolerance = scale_data[channel];
+            assert!((x - y).abs() <= tolerance, "channel {}: {} round-tripped to {}", channel, x, y);
+        }
+    }
+}
+
+#[test]
+fn strided_copy_half() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<f16> = (0..128).map(|x| f16::from_f32(x as f32)).collect_vec();
+    let mut output_data = vec![f16::ZERO; 128];
+
+    let input = device.alloc(input_data.len() * 2);
+    let output = device.alloc(output_data.len() * 2);
+
+    let rank = 4;
+    let size = 56;
+    let input_strides: Vec<i32> = vec![64, 8, 0, 2];
+    let output_strides: Vec<i32> = vec![24, 8, 4, 1];
+    let dense_strides: Vec<i32> = vec![24, 8, 4, 1];
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+
+        kernels::stridedCopyHalf(
+            stream.inner(),
+            rank,
+            size,
+            input_strides.as_ptr(),
+            output_strides.as_ptr(),
+            dense_strides.as_ptr(),
+            input.ptr() as *const f16,
+            output.ptr() as *mut f16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    println!("{:?}", output_data);
+}
+
+#[test]
+fn strided_copy_bf16() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<bf16> = (0..128).map(|x| bf16::from_f32(x as f32)).collect_vec();
+    let mut output_data = vec![bf16::ZERO; 128];
+
+    let input = device.alloc(input_data.len() * 2);
+    let output = device.alloc(output_data.len() * 2);
+
+    let rank = 4;
+    let size = 56;
+    let input_strides: Vec<i32> = vec![64, 8, 0, 2];
+    let output_strides: Vec<i32> = vec![24, 8, 4, 1];
+    let dense_strides: Vec<i32> = vec![24, 8, 4, 1];
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+
+        kernels::stridedCopyBFloat16(
+            stream.inner(),
+            rank,
+            size,
+            input_strides.as_ptr(),
+            output_strides.as_ptr(),
+            dense_strides.as_ptr(),
+            input.ptr() as *const bf16,
+            output.ptr() as *mut bf16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    println!("{:?}", output_data);
+}
+
+#[test]
+fn gather_half() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<f16> = (0..128).map(|x| f16::from_f32(x as f32)).collect_vec();
+    let indices_data: Vec<i32> = vec![16, 3, 8, 2, 4, 9];
+    let mut output_data = vec![f16::ZERO; indices_data.len()];
+
+    let input = device.alloc(input_data.len() * 2);
+    let indices = device.alloc(indices_data.len() * 4);
+    let output = device.alloc(output_data.len() * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+
+        kernels::gatherHalf(
+            stream.inner(),
+            indices_data.len() as i32,
+            indices.ptr() as *const i32,
+            input.ptr() as *const f16,
+            output.ptr() as *mut f16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let expected_output_data = indices_data.iter().map(|&x| f16::from_f32(x as f32)).collect_vec();
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn gather_bf16() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<bf16> = (0..128).map(|x| bf16::from_f32(x as f32)).collect_vec();
+    let indices_data: Vec<i32> = vec![16, 3, 8, 2, 4, 9];
+    let mut output_data = vec![bf16::ZERO; indices_data.len()];
+
+    let input = device.alloc(input_data.len() * 2);
+    let indices = device.alloc(indices_data.len() * 4);
+    let output = device.alloc(output_data.len() * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+
+        kernels::gatherBFloat16(
+            stream.inner(),
+            indices_data.len() as i32,
+            indices.ptr() as *const i32,
+            input.ptr() as *const bf16,
+            output.ptr() as *mut bf16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let expected_output_data = indices_data.iter().map(|&x| bf16::from_f32(x as f32)).collect_vec();
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn gather_2d_axis1_half() {
+    for batch_size in [0, 1, 2, 3, 4, 8, 13] {
+        for input_size in [1, 2, 3, 4, 128, 129, 1000] {
+            for index_count in [0, 1, 2, 3, 63, 64, 65, 127, 128, 129, 1000] {
+                gather_2d_axis1_half_impl(batch_size, input_size, index_count);
+            }
+        }
+    }
+}
+
+/// f16 values up to 1000 represent small integers exactly, so the gathered output is expected to
+/// be bit-for-bit identical to the reference, not just close.
+fn gather_2d_axis1_half_impl(batch_size: usize, input_size: usize, index_count: usize) {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    let input_data: Vec<f16> = (0..batch_size * input_size).map(|x| f16::from_f32(-(x as f32))).collect_vec();
+
+    let mut index_rng = StdRng::seed_from_u64(1);
+    let indices_data: Vec<f16> = (0..index_count)
+        .map(|_| f16::from_f32(index_rng.gen_range(0..input_size) as f32))
+        .collect_vec();
+
+    let mut output_data: Vec<f16> = vec![f16::ZERO; batch_size * indices_data.len()];
+
+    let input = device.alloc(input_data.len() * 2);
+    let indices = device.alloc(indices_data.len() * 2);
+    let output = device.alloc(output_data.len() * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+
+        kernels::gather2dAxis1HalfHalf(
+            stream.inner(),
+            batch_size as i32,
+            input_size as i32,
+            input_size as i32,
+            1,
+            indices_data.len() as i32,
+            input.ptr() as *const f16,
+            indices.ptr() as *const f16,
+            output.ptr() as *mut f16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let expected_output_data = (0..batch_size)
+        .flat_map(|n| {
+            indices_data
+                .iter()
+                .map(|&i| input_data[n * input_size + i.to_f32() as usize])
+                .collect_vec()
+        })
+        .collect_vec();
+
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn gather_2d_axis1_bf16() {
+    for batch_size in [0, 1, 2, 3, 4, 8, 13] {
+        for input_size in [1, 2, 3, 4, 128, 129, 1000] {
+            for index_count in [0, 1, 2, 3, 63, 64, 65, 127, 128, 129, 1000] {
+                gather_2d_axis1_bf16_impl(batch_size, input_size, index_count);
+            }
+        }
+    }
+}
+
+fn gather_2d_axis1_bf16_impl(batch_size: usize, input_size: usize, index_count: usize) {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    // bf16 only has 8 bits of mantissa, so values are kept small enough (< 256) to stay exact.
+    let input_data: Vec<bf16> = (0..batch_size * input_size).map(|x| bf16::from_f32(-((x % 256) as f32))).collect_vec();
+
+    let mut index_rng = StdRng::seed_from_u64(1);
+    // Indices above 256 are not all representable in bf16 and can round up past the end of the
+    // row (999 rounds to 1000), so step such an index down to the next smaller bf16 value.
+    let indices_data: Vec<bf16> = (0..index_count)
+        .map(|_| {
+            let index = bf16::from_f32(index_rng.gen_range(0..input_size) as f32);
+            if index.to_f32() as usize >= input_size {
+                bf16::from_bits(index.to_bits() - 1)
+            } else {
+                index
+            }
+        })
+        .collect_vec();
+
+    let mut output_data: Vec<bf16> = vec![bf16::ZERO; batch_size * indices_data.len()];
+
+    let input = device.alloc(input_data.len() * 2);
+    let indices = device.alloc(indices_data.len() * 2);
+    let output = device.alloc(output_data.len() * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        indices.copy_linear_from_host(cast_slice(&indices_data));
+
+        kernels::gather2dAxis1BFloat16BFloat16(
+            stream.inner(),
+            batch_size as i32,
+            input_size as i32,
+            input_size as i32,
+            1,
+            indices_data.len() as i32,
+            input.ptr() as *const bf16,
+            indices.ptr() as *const bf16,
+            output.ptr() as *mut bf16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+    }
+
+    let expected_output_data = (0..batch_size)
+        .flat_map(|n| {
+            indices_data
+                .iter()
+                .map(|&i| input_data[n * input_size + i.to_f32() as usize])
+                .collect_vec()
+        })
+        .collect_vec();
+
+    assert_eq!(output_data, expected_output_data);
+}
+
+#[test]
+fn quantize_affine_half() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    // Same two-range-per-channel setup as `quantize_affine`, but the input tensor itself is
+    // stored in f16 (scale/zero_point stay f32, same as the other quantize kernels).
+    let channel_count = 2;
+    let channel_stride = 16;
+    let length = channel_count * channel_stride;
+
+    let input_data_f32: Vec<f32> = (0..length)
+        .map(|i| {
+            let channel = i / channel_stride;
+            let x = (i % channel_stride) as f32 / (channel_stride - 1) as f32;
+            if channel == 0 {
+                x * 2.0 - 1.0
+            } else {
+                x * 200.0 - 50.0
+            }
+        })
+        .collect_vec();
+    let input_data: Vec<f16> = input_data_f32.iter().map(|&x| f16::from_f32(x)).collect_vec();
+
+    let scale_data: Vec<f32> = (0..channel_count)
+        .map(|c| {
+            let slice = &input_data_f32[c * channel_stride..(c + 1) * channel_stride];
+            let lo = slice.iter().copied().fold(f32::INFINITY, f32::min);
+            let hi = slice.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            (hi - lo) / 255.0
+        })
+        .collect_vec();
+    let zero_point_data: Vec<i32> = (0..channel_count)
+        .map(|c| {
+            let slice = &input_data_f32[c * channel_stride..(c + 1) * channel_stride];
+            let lo = slice.iter().copied().fold(f32::INFINITY, f32::min);
+            (-lo / scale_data[c]).round() as i32
+        })
+        .collect_vec();
+
+    let input = device.alloc(input_data.len() * 2);
+    let scale = device.alloc(channel_count * 4);
+    let zero_point = device.alloc(channel_count * 4);
+    let middle = device.alloc(length);
+    let output = device.alloc(length * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        scale.copy_linear_from_host(cast_slice(&scale_data));
+        zero_point.copy_linear_from_host(cast_slice(&zero_point_data));
+
+        kernels::quantizeAffineHalf(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            input.ptr() as *const f16,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            middle.ptr() as *mut u8,
+        )
+        .unwrap();
+        kernels::dequantizeAffineHalf(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            middle.ptr() as *const u8,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            output.ptr() as *mut f16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        let mut output_data = vec![f16::ZERO; length];
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+
+        for (i, (&x, &y)) in input_data_f32.iter().zip(&output_data).enumerate() {
+            let channel = i / channel_stride;
+            // f16 only has ~3 decimal digits of precision, so allow a little extra slack on top
+            // of the quantization step itself.
+            let tolerance = scale_data[channel] + 0.5;
+            assert!((x - y.to_f32()).abs() <= tolerance, "channel {}: {} round-tripped to {}", channel, x, y.to_f32());
+        }
+    }
+}
+
+#[test]
+fn quantize_affine_bf16() {
+    let device = Device::new(0);
+    let stream = CudaStream::new(device);
+
+    // Same setup as `quantize_affine_half`, but the input tensor is stored in bf16.
+    let channel_count = 2;
+    let channel_stride = 16;
+    let length = channel_count * channel_stride;
+
+    let input_data_f32: Vec<f32> = (0..length)
+        .map(|i| {
+            let channel = i / channel_stride;
+            let x = (i % channel_stride) as f32 / (channel_stride - 1) as f32;
+            if channel == 0 {
+                x * 2.0 - 1.0
+            } else {
+                x * 200.0 - 50.0
+            }
+        })
+        .collect_vec();
+    let input_data: Vec<bf16> = input_data_f32.iter().map(|&x| bf16::from_f32(x)).collect_vec();
+
+    let scale_data: Vec<f32> = (0..channel_count)
+        .map(|c| {
+            let slice = &input_data_f32[c * channel_stride..(c + 1) * channel_stride];
+            let lo = slice.iter().copied().fold(f32::INFINITY, f32::min);
+            let hi = slice.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            (hi - lo) / 255.0
+        })
+        .collect_vec();
+    let zero_point_data: Vec<i32> = (0..channel_count)
+        .map(|c| {
+            let slice = &input_data_f32[c * channel_stride..(c + 1) * channel_stride];
+            let lo = slice.iter().copied().fold(f32::INFINITY, f32::min);
+            (-lo / scale_data[c]).round() as i32
+        })
+        .collect_vec();
+
+    let input = device.alloc(input_data.len() * 2);
+    let scale = device.alloc(channel_count * 4);
+    let zero_point = device.alloc(channel_count * 4);
+    let middle = device.alloc(length);
+    let output = device.alloc(length * 2);
+
+    unsafe {
+        input.copy_linear_from_host(cast_slice(&input_data));
+        scale.copy_linear_from_host(cast_slice(&scale_data));
+        zero_point.copy_linear_from_host(cast_slice(&zero_point_data));
+
+        kernels::quantizeAffineBFloat16(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            input.ptr() as *const bf16,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            middle.ptr() as *mut u8,
+        )
+        .unwrap();
+        kernels::dequantizeAffineBFloat16(
+            stream.inner(),
+            length as i32,
+            channel_count as i32,
+            channel_stride as i32,
+            middle.ptr() as *const u8,
+            scale.ptr() as *const f32,
+            zero_point.ptr() as *const i32,
+            output.ptr() as *mut bf16,
+        )
+        .unwrap();
+        stream.synchronize();
+
+        let mut output_data = vec![bf16::ZERO; length];
+        output.copy_linear_to_host(cast_slice_mut(&mut output_data));
+
+        for (i, (&x, &y)) in input_data_f32.iter().zip(&output_data).enumerate() {
+            let channel = i / channel_stride;
+            // bf16 only has ~2-3 decimal digits of precision (8-bit mantissa), so allow a bit
+            // more slack than the f16 variant on top of the quantization step itself.
+            let tolerance = scale_data[channel] + 1.0;
+            assert!((x - y.to_f32()).abs() <= tolerance, "channel {}: {} round-tripped to {}", channel, x, y.to_f32());
+        }
+    }
+}